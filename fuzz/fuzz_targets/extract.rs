@@ -0,0 +1,44 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use cargo_mutants::{extract, f, f_owned, pairs, try_extract_pair, Enum, ExtractError, Struct};
+use libfuzzer_sys::fuzz_target;
+
+/// Mirror of [Enum] that can be generated from fuzzer input.
+#[derive(Arbitrary, Debug)]
+enum Input {
+    Variant,
+    Number(i64),
+    Text(String),
+}
+
+impl From<Input> for Enum {
+    fn from(input: Input) -> Enum {
+        match input {
+            Input::Variant => Enum::Variant(Struct),
+            Input::Number(n) => Enum::Number(n),
+            Input::Text(s) => Enum::Text(s),
+        }
+    }
+}
+
+fuzz_target!(|inputs: Vec<Input>| {
+    let args: Vec<Enum> = inputs.into_iter().map(Enum::from).collect();
+    let is_variant = |arg: &Enum| matches!(arg, Enum::Variant(_));
+    let expected = args.len() == 2 && args.iter().all(is_variant);
+
+    assert_eq!(f(&args).is_some(), expected);
+    assert_eq!(f_owned(&args).is_some(), expected);
+    assert_eq!(extract::<2>(&args).is_some(), expected);
+    match try_extract_pair(&args) {
+        Ok(_) => assert!(expected),
+        Err(ExtractError::WrongLength(len)) => assert!(len == args.len() && len != 2),
+        Err(ExtractError::WrongVariant(i)) => assert!(!is_variant(&args[i])),
+    }
+    assert_eq!(
+        pairs(&args).count(),
+        args.windows(2)
+            .filter(|window| window.iter().all(is_variant))
+            .count()
+    );
+});
@@ -1,10 +1,61 @@
 //! Reproduction of rust-analyzer #17039.
 
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Struct;
+
+/// An argument to [f]; only [Enum::Variant] carries a [Struct].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Enum {
     Variant(Struct),
+    Number(i64),
+    Text(String),
+}
+
+/// Why a slice of [Enum] could not be converted to `Struct` payloads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtractError {
+    /// The slice had this many elements rather than the number expected.
+    WrongLength(usize),
+    /// The element at this index is not an [Enum::Variant].
+    WrongVariant(usize),
+}
+
+impl fmt::Display for ExtractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExtractError::WrongLength(len) => write!(f, "wrong number of arguments: {len}"),
+            ExtractError::WrongVariant(i) => write!(f, "argument {i} is not Enum::Variant"),
+        }
+    }
+}
+
+impl std::error::Error for ExtractError {}
+
+fn payload(arg: &Enum) -> Option<&Struct> {
+    match arg {
+        Enum::Variant(thing) => Some(thing),
+        _ => None,
+    }
 }
 
+/// Extract the payloads of a two-element slice of [Enum::Variant]s, using
+/// [itertools::Itertools::collect_tuple].
+///
+/// ```
+/// use cargo_mutants::{f, Enum, Struct};
+///
+/// let v = || Enum::Variant(Struct);
+/// assert_eq!(f(&[v(), v()]), Some((&Struct, &Struct)));
+/// assert_eq!(f(&[]), None);
+/// assert_eq!(f(&[v()]), None);
+/// assert_eq!(f(&[v(), v(), v()]), None);
+/// assert_eq!(f(&[v(), Enum::Number(0)]), None);
+/// ```
+#[cfg(feature = "itertools")]
 pub fn f(args: &[Enum]) -> Option<(&Struct, &Struct)> {
     use itertools::Itertools;
     let arg_iter = args.iter();
@@ -13,3 +64,166 @@ pub fn f(args: &[Enum]) -> Option<(&Struct, &Struct)> {
     }
     None
 }
+
+/// Extract the payloads of a two-element slice of [Enum::Variant]s, matching
+/// on the slice directly.
+///
+/// ```
+/// use cargo_mutants::{f, Enum, Struct};
+///
+/// let v = || Enum::Variant(Struct);
+/// assert_eq!(f(&[v(), v()]), Some((&Struct, &Struct)));
+/// assert_eq!(f(&[]), None);
+/// assert_eq!(f(&[v()]), None);
+/// assert_eq!(f(&[v(), v(), v()]), None);
+/// assert_eq!(f(&[v(), Enum::Number(0)]), None);
+/// ```
+#[cfg(not(feature = "itertools"))]
+pub fn f(args: &[Enum]) -> Option<(&Struct, &Struct)> {
+    if let [Enum::Variant(thing), Enum::Variant(thing2)] = args {
+        return Some((thing, thing2));
+    }
+    None
+}
+
+/// Like [f], but clone the payloads so they outlive `args`.
+///
+/// ```
+/// use cargo_mutants::{f_owned, Enum, Struct};
+///
+/// let args = vec![Enum::Variant(Struct), Enum::Variant(Struct)];
+/// let pair = f_owned(&args);
+/// drop(args);
+/// assert_eq!(pair, Some((Struct, Struct)));
+/// ```
+pub fn f_owned(args: &[Enum]) -> Option<(Struct, Struct)> {
+    f(args).map(|(a, b)| (a.clone(), b.clone()))
+}
+
+/// Extract the payloads of exactly `N` [Enum::Variant]s, or `None` if there are
+/// not exactly `N` elements or any element is another variant.
+///
+/// ```
+/// use cargo_mutants::{extract, Enum, Struct};
+///
+/// let args = [(); 4].map(|_| Enum::Variant(Struct));
+/// assert!(extract::<1>(&args[..1]).is_some());
+/// assert!(extract::<2>(&args[..2]).is_some());
+/// assert!(extract::<3>(&args[..3]).is_some());
+/// assert!(extract::<4>(&args).is_some());
+/// assert!(extract::<4>(&args[..3]).is_none());
+/// assert!(extract::<2>(&[Enum::Variant(Struct), Enum::Number(0)]).is_none());
+/// ```
+pub fn extract<const N: usize>(args: &[Enum]) -> Option<[&Struct; N]> {
+    if args.len() != N {
+        return None;
+    }
+    args.iter()
+        .map(payload)
+        .collect::<Option<Vec<_>>>()?
+        .try_into()
+        .ok()
+}
+
+/// Like [f], but report whether the length or a variant was wrong.
+///
+/// ```
+/// use cargo_mutants::{try_extract_pair, Enum, ExtractError, Struct};
+///
+/// let args = [Enum::Variant(Struct), Enum::Variant(Struct)];
+/// assert_eq!(try_extract_pair(&args), Ok((&Struct, &Struct)));
+///
+/// let args = [Enum::Variant(Struct), Enum::Number(1)];
+/// assert_eq!(try_extract_pair(&args).err(), Some(ExtractError::WrongVariant(1)));
+/// assert_eq!(try_extract_pair(&args[..1]).err(), Some(ExtractError::WrongLength(1)));
+///
+/// let args = [Enum::Text("a".into()), Enum::Variant(Struct)];
+/// assert_eq!(try_extract_pair(&args).err(), Some(ExtractError::WrongVariant(0)));
+/// ```
+pub fn try_extract_pair(args: &[Enum]) -> Result<(&Struct, &Struct), ExtractError> {
+    let [a, b] = args else {
+        return Err(ExtractError::WrongLength(args.len()));
+    };
+    let a = payload(a).ok_or(ExtractError::WrongVariant(0))?;
+    let b = payload(b).ok_or(ExtractError::WrongVariant(1))?;
+    Ok((a, b))
+}
+
+/// Payloads of every adjacent pair of elements that are both [Enum::Variant].
+///
+/// ```
+/// use cargo_mutants::{pairs, Enum, Struct};
+///
+/// let args = [
+///     Enum::Variant(Struct),
+///     Enum::Variant(Struct),
+///     Enum::Variant(Struct),
+///     Enum::Number(1),
+///     Enum::Variant(Struct),
+/// ];
+/// assert_eq!(pairs(&args).count(), 2);
+/// ```
+pub fn pairs(args: &[Enum]) -> impl Iterator<Item = (&Struct, &Struct)> {
+    args.windows(2)
+        .filter_map(|window| try_extract_pair(window).ok())
+}
+
+/// The payloads of a two-element slice, as a [TryFrom] counterpart to [f].
+///
+/// The orphan rule forbids implementing [TryFrom] directly for the tuple, so
+/// convert through this and then into `(&Struct, &Struct)`:
+///
+/// ```
+/// use cargo_mutants::{Enum, ExtractError, Pair, Struct};
+///
+/// fn both(args: &[Enum]) -> Result<(&Struct, &Struct), ExtractError> {
+///     Ok(Pair::try_from(args)?.into())
+/// }
+///
+/// let args = [Enum::Variant(Struct), Enum::Variant(Struct)];
+/// assert_eq!(Pair::try_from(&args[..]), Ok(Pair(&Struct, &Struct)));
+/// assert!(both(&args).is_ok());
+/// assert_eq!(both(&[]).err(), Some(ExtractError::WrongLength(0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pair<'a>(pub &'a Struct, pub &'a Struct);
+
+impl<'a> TryFrom<&'a [Enum]> for Pair<'a> {
+    type Error = ExtractError;
+
+    fn try_from(args: &'a [Enum]) -> Result<Self, Self::Error> {
+        let (a, b) = try_extract_pair(args)?;
+        Ok(Pair(a, b))
+    }
+}
+
+impl<'a> From<Pair<'a>> for (&'a Struct, &'a Struct) {
+    fn from(Pair(a, b): Pair<'a>) -> Self {
+        (a, b)
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    fn round_trip(value: Enum) {
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<Enum>(&json).unwrap(), value);
+    }
+
+    #[test]
+    fn serde_round_trip_variant() {
+        round_trip(Enum::Variant(Struct));
+    }
+
+    #[test]
+    fn serde_round_trip_number() {
+        round_trip(Enum::Number(-1));
+    }
+
+    #[test]
+    fn serde_round_trip_text() {
+        round_trip(Enum::Text("text".into()));
+    }
+}